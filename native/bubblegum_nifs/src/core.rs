@@ -1,20 +1,30 @@
 use crate::utils::vec_to_array32;
 use anchor_lang::prelude::AccountMeta;
+use borsh::BorshSerialize;
 use mpl_bubblegum::accounts::TreeConfig;
 use mpl_bubblegum::instructions::{
-    CreateTreeConfig, CreateTreeConfigInstructionArgs, MintToCollectionV1,
-    MintToCollectionV1InstructionArgs, MintV1, MintV1InstructionArgs, Transfer,
-    TransferInstructionArgs,
+    Burn, BurnInstructionArgs, CancelRedeem, CancelRedeemInstructionArgs, CreateTreeConfig,
+    CreateTreeConfigInstructionArgs, DecompressV1, DecompressV1InstructionArgs, Delegate,
+    DelegateInstructionArgs, MintToCollectionV1, MintToCollectionV1InstructionArgs, MintV1,
+    MintV1InstructionArgs, Redeem, RedeemInstructionArgs, SetAndVerifyCollection,
+    SetAndVerifyCollectionInstructionArgs, Transfer, TransferInstructionArgs, UnverifyCollection,
+    UnverifyCollectionInstructionArgs, UnverifyCreator, UnverifyCreatorInstructionArgs,
+    VerifyCollection, VerifyCollectionInstructionArgs, VerifyCreator, VerifyCreatorInstructionArgs,
 };
 use mpl_bubblegum::types::{Creator, MetadataArgs};
 use mpl_token_metadata::accounts::{MasterEdition, Metadata};
-use rustler::{Error, NifResult, NifStruct};
+use rustler::{Error, NifResult, NifStruct, ResourceArc};
+use solana_program::keccak;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::address_lookup_table_account::AddressLookupTableAccount;
 use solana_sdk::hash::Hash;
-use solana_sdk::signature::Keypair;
+use solana_sdk::message::{v0, VersionedMessage};
+use solana_sdk::signature::{Keypair, Signature};
 use solana_sdk::signer::Signer;
-use solana_sdk::transaction::Transaction;
+use solana_sdk::transaction::{Transaction, VersionedTransaction};
+use std::collections::{HashMap, VecDeque};
 use std::str::FromStr;
+use std::sync::Mutex;
 
 #[derive(NifStruct)]
 #[module = "BubblegumNifs.KeyPairInfo"]
@@ -61,11 +71,22 @@ pub struct MetadataArgsStruct {
     pub uses: Option<UsesStruct>,
 }
 
+#[derive(NifStruct)]
+#[module = "BubblegumNifs.LookupTable"]
+pub struct LookupTableStruct {
+    pub key: String,
+    pub addresses: Vec<String>,
+}
+
 #[derive(NifStruct)]
 #[module = "BubblegumNifs.Transaction"]
 pub struct TransactionStruct {
     pub message: Vec<u8>,
     pub signatures: Vec<Vec<u8>>,
+    // Ordered list of the pubkeys expected to sign this transaction. For a
+    // fully signed transaction this mirrors `signatures`; for an unsigned one it
+    // tells an external/remote signer which keys to produce signatures for.
+    pub signers: Vec<String>,
 }
 
 fn to_rust_creator(creator: &CreatorStruct) -> Result<Creator, Error> {
@@ -131,6 +152,80 @@ fn to_rust_metadata_args(args: &MetadataArgsStruct) -> Result<MetadataArgs, Erro
     })
 }
 
+fn push_proof_accounts(
+    accounts: &mut Vec<AccountMeta>,
+    proof_addresses: Vec<String>,
+) -> Result<(), Error> {
+    for proof_address in proof_addresses {
+        let proof_pubkey = Pubkey::from_str(&proof_address)
+            .map_err(|_| Error::Term(Box::new("Invalid pubkey format for proof address")))?;
+        accounts.push(AccountMeta::new_readonly(proof_pubkey, false));
+    }
+    Ok(())
+}
+
+fn build_signed_transaction(
+    instruction: solana_program::instruction::Instruction,
+    payer: &Keypair,
+    blockhash: Hash,
+) -> NifResult<TransactionStruct> {
+    // Some of these instructions (e.g. burn/delegate) mark a non-payer account
+    // as a required signer. Sign through `try_sign` so a missing authority
+    // returns an `Error::Term` the caller can handle instead of panicking; such
+    // callers should reach for the unsigned builders and attach the remaining
+    // signatures with `add_signature` / `sign_transaction`.
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&payer.pubkey()));
+    transaction.try_sign(&[payer], blockhash).map_err(|_| {
+        Error::Term(Box::new(
+            "Failed to sign transaction: a required signer is missing (use the unsigned builder)",
+        ))
+    })?;
+
+    let serialized_tx = bincode::serialize(&transaction)
+        .map_err(|_| Error::Term(Box::new("Failed to serialize transaction")))?;
+
+    Ok(TransactionStruct {
+        message: serialized_tx,
+        signatures: transaction
+            .signatures
+            .iter()
+            .map(|sig| sig.as_ref().to_vec())
+            .collect(),
+        signers: vec![payer.pubkey().to_string()],
+    })
+}
+
+// Compile `instructions` into a legacy message and return it unsigned: the
+// serialized message, one empty signature slot per required signer, and the
+// ordered signer pubkeys so signatures can be produced elsewhere (HSM, remote
+// custody) and attached later with `add_signature` / `sign_transaction`.
+fn build_unsigned_transaction(
+    instructions: &[solana_program::instruction::Instruction],
+    payer: &Pubkey,
+    blockhash: Hash,
+) -> NifResult<TransactionStruct> {
+    let mut message = solana_program::message::Message::new(instructions, Some(payer));
+    message.recent_blockhash = blockhash;
+
+    // The first `num_required_signatures` account keys are exactly the accounts
+    // that must sign, in the order their signatures are expected.
+    let signers: Vec<String> = message
+        .account_keys
+        .iter()
+        .take(message.header.num_required_signatures as usize)
+        .map(|pubkey| pubkey.to_string())
+        .collect();
+
+    let serialized_message = bincode::serialize(&message)
+        .map_err(|_| Error::Term(Box::new("Failed to serialize message")))?;
+
+    Ok(TransactionStruct {
+        message: serialized_message,
+        signatures: vec![Vec::new(); signers.len()],
+        signers,
+    })
+}
+
 #[rustler::nif]
 pub fn generate_keypair() -> NifResult<KeyPairInfo> {
     let keypair = Keypair::new();
@@ -208,6 +303,10 @@ pub fn create_tree_config_ix(
             .iter()
             .map(|sig| sig.as_ref().to_vec())
             .collect(),
+        signers: vec![
+            payer_keypair.pubkey().to_string(),
+            merkle_tree_keypair.pubkey().to_string(),
+        ],
     })
 }
 
@@ -270,6 +369,7 @@ pub fn mint_v1_ix(
             .iter()
             .map(|sig| sig.as_ref().to_vec())
             .collect(),
+        signers: vec![payer.pubkey().to_string()],
     })
 }
 
@@ -370,6 +470,7 @@ pub fn mint_to_collection_v1_ix(
             .iter()
             .map(|sig| sig.as_ref().to_vec())
             .collect(),
+        signers: vec![payer.pubkey().to_string()],
     })
 }
 
@@ -463,14 +564,1598 @@ pub fn transfer_ix(
             .iter()
             .map(|sig| sig.as_ref().to_vec())
             .collect(),
+        signers: vec![payer.pubkey().to_string()],
     })
 }
 
 #[rustler::nif]
-pub fn get_tree_authority_pda_address(merkle_tree: String) -> NifResult<String> {
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_v0_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    new_leaf_owner: String,
+    merkle_tree: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    lookup_tables: Vec<LookupTableStruct>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let new_leaf_owner = Pubkey::from_str(&new_leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for new leaf owner")))?;
+
     let merkle_tree = Pubkey::from_str(&merkle_tree)
         .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
 
-    let (tree_authority, _) = TreeConfig::find_pda(&merkle_tree);
-    Ok(tree_authority.to_string())
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut transfer_ix = Transfer {
+        tree_config: tree_authority,
+        leaf_owner: (leaf_owner, true),
+        leaf_delegate: (leaf_delegate, true),
+        new_leaf_owner,
+        merkle_tree,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(TransferInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    for proof_address in proof_addresses {
+        let proof_pubkey = Pubkey::from_str(&proof_address)
+            .map_err(|_| Error::Term(Box::new("Invalid pubkey format for proof address")))?;
+        transfer_ix
+            .accounts
+            .push(AccountMeta::new_readonly(proof_pubkey, false));
+    }
+
+    // Resolve the supplied lookup tables so the long proof account list can be
+    // referenced by index instead of being inlined in the message.
+    let mut address_lookup_tables = Vec::with_capacity(lookup_tables.len());
+    for lookup_table in lookup_tables {
+        let key = Pubkey::from_str(&lookup_table.key)
+            .map_err(|_| Error::Term(Box::new("Invalid pubkey format for lookup table")))?;
+
+        let mut addresses = Vec::with_capacity(lookup_table.addresses.len());
+        for address in lookup_table.addresses {
+            let address = Pubkey::from_str(&address).map_err(|_| {
+                Error::Term(Box::new("Invalid pubkey format for lookup table address"))
+            })?;
+            addresses.push(address);
+        }
+
+        address_lookup_tables.push(AddressLookupTableAccount { key, addresses });
+    }
+
+    let message = v0::Message::try_compile(
+        &payer.pubkey(),
+        &[transfer_ix],
+        &address_lookup_tables,
+        blockhash,
+    )
+    .map_err(|_| Error::Term(Box::new("Failed to compile v0 message")))?;
+
+    let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&payer])
+        .map_err(|_| Error::Term(Box::new("Failed to sign versioned transaction")))?;
+
+    let serialized_tx = bincode::serialize(&transaction)
+        .map_err(|_| Error::Term(Box::new("Failed to serialize transaction")))?;
+
+    Ok(TransactionStruct {
+        message: serialized_tx,
+        signatures: transaction
+            .signatures
+            .iter()
+            .map(|sig| sig.as_ref().to_vec())
+            .collect(),
+        signers: vec![payer.pubkey().to_string()],
+    })
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn burn_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut burn_ix = Burn {
+        tree_config: tree_authority,
+        leaf_owner: (leaf_owner, true),
+        leaf_delegate: (leaf_delegate, true),
+        merkle_tree,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(BurnInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    push_proof_accounts(&mut burn_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(burn_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn delegate_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    previous_leaf_delegate: String,
+    new_leaf_delegate: String,
+    merkle_tree: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let previous_leaf_delegate = Pubkey::from_str(&previous_leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for previous leaf delegate")))?;
+
+    let new_leaf_delegate = Pubkey::from_str(&new_leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for new leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut delegate_ix = Delegate {
+        tree_config: tree_authority,
+        leaf_owner,
+        previous_leaf_delegate,
+        new_leaf_delegate,
+        merkle_tree,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(DelegateInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    push_proof_accounts(&mut delegate_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(delegate_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn redeem_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    voucher: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let voucher = Pubkey::from_str(&voucher)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for voucher")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut redeem_ix = Redeem {
+        tree_config: tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        voucher,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(RedeemInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    push_proof_accounts(&mut redeem_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(redeem_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn cancel_redeem_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    merkle_tree: String,
+    voucher: String,
+    root_hash: Vec<u8>,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let voucher = Pubkey::from_str(&voucher)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for voucher")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut cancel_redeem_ix = CancelRedeem {
+        tree_config: tree_authority,
+        leaf_owner,
+        merkle_tree,
+        voucher,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(CancelRedeemInstructionArgs { root });
+
+    push_proof_accounts(&mut cancel_redeem_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(cancel_redeem_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn decompress_v1_ix(
+    voucher: String,
+    leaf_owner: String,
+    token_account: String,
+    mint: String,
+    mint_authority: String,
+    metadata: String,
+    master_edition: String,
+    metadata_args: MetadataArgsStruct,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let voucher = Pubkey::from_str(&voucher)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for voucher")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let token_account = Pubkey::from_str(&token_account)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for token account")))?;
+
+    let mint = Pubkey::from_str(&mint)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for mint")))?;
+
+    let mint_authority = Pubkey::from_str(&mint_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for mint authority")))?;
+
+    let metadata_account = Pubkey::from_str(&metadata)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for metadata")))?;
+
+    let master_edition = Pubkey::from_str(&master_edition)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for master edition")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let metadata = to_rust_metadata_args(&metadata_args)?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let decompress_ix = DecompressV1 {
+        voucher,
+        leaf_owner,
+        token_account,
+        mint,
+        mint_authority,
+        metadata: metadata_account,
+        master_edition,
+        system_program: solana_program::system_program::ID,
+        sysvar_rent: solana_program::sysvar::rent::ID,
+        token_metadata_program: mpl_token_metadata::ID,
+        token_program: spl_token::ID,
+        associated_token_program: spl_associated_token_account::ID,
+        log_wrapper: spl_noop::ID,
+    }
+    .instruction(DecompressV1InstructionArgs { metadata });
+
+    build_signed_transaction(decompress_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn verify_creator_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    creator: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    metadata_args: MetadataArgsStruct,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let creator = Pubkey::from_str(&creator)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for creator")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let message = to_rust_metadata_args(&metadata_args)?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut verify_creator_ix = VerifyCreator {
+        tree_config: tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        payer: payer.pubkey(),
+        creator,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(VerifyCreatorInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+    });
+
+    push_proof_accounts(&mut verify_creator_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(verify_creator_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn unverify_creator_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    creator: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    metadata_args: MetadataArgsStruct,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let creator = Pubkey::from_str(&creator)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for creator")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let message = to_rust_metadata_args(&metadata_args)?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut unverify_creator_ix = UnverifyCreator {
+        tree_config: tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        payer: payer.pubkey(),
+        creator,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(UnverifyCreatorInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+    });
+
+    push_proof_accounts(&mut unverify_creator_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(unverify_creator_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn verify_collection_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    collection_authority: String,
+    collection_mint: String,
+    collection_metadata: String,
+    collection_master_edition: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    metadata_args: MetadataArgsStruct,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let collection_authority = Pubkey::from_str(&collection_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection authority")))?;
+
+    let collection_mint = Pubkey::from_str(&collection_mint)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection mint")))?;
+
+    let collection_metadata = if collection_metadata.is_empty() {
+        Metadata::find_pda(&collection_mint).0
+    } else {
+        Pubkey::from_str(&collection_metadata)
+            .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection metadata")))?
+    };
+
+    let collection_edition = if collection_master_edition.is_empty() {
+        MasterEdition::find_pda(&collection_mint).0
+    } else {
+        Pubkey::from_str(&collection_master_edition).map_err(|_| {
+            Error::Term(Box::new(
+                "Invalid pubkey format for collection master edition",
+            ))
+        })?
+    };
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let message = to_rust_metadata_args(&metadata_args)?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let bubblegum_signer_seeds = &["collection_cpi".as_bytes()];
+    let (bubblegum_signer, _) =
+        Pubkey::find_program_address(bubblegum_signer_seeds, &mpl_bubblegum::ID);
+
+    let mut verify_collection_ix = VerifyCollection {
+        tree_config: tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        payer: payer.pubkey(),
+        tree_creator_or_delegate: payer.pubkey(),
+        collection_authority,
+        collection_authority_record_pda: None,
+        collection_mint,
+        collection_metadata,
+        collection_edition,
+        bubblegum_signer,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        token_metadata_program: mpl_token_metadata::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(VerifyCollectionInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+    });
+
+    push_proof_accounts(&mut verify_collection_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(verify_collection_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn unverify_collection_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    collection_authority: String,
+    collection_mint: String,
+    collection_metadata: String,
+    collection_master_edition: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    metadata_args: MetadataArgsStruct,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let collection_authority = Pubkey::from_str(&collection_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection authority")))?;
+
+    let collection_mint = Pubkey::from_str(&collection_mint)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection mint")))?;
+
+    let collection_metadata = if collection_metadata.is_empty() {
+        Metadata::find_pda(&collection_mint).0
+    } else {
+        Pubkey::from_str(&collection_metadata)
+            .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection metadata")))?
+    };
+
+    let collection_edition = if collection_master_edition.is_empty() {
+        MasterEdition::find_pda(&collection_mint).0
+    } else {
+        Pubkey::from_str(&collection_master_edition).map_err(|_| {
+            Error::Term(Box::new(
+                "Invalid pubkey format for collection master edition",
+            ))
+        })?
+    };
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let message = to_rust_metadata_args(&metadata_args)?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let bubblegum_signer_seeds = &["collection_cpi".as_bytes()];
+    let (bubblegum_signer, _) =
+        Pubkey::find_program_address(bubblegum_signer_seeds, &mpl_bubblegum::ID);
+
+    let mut unverify_collection_ix = UnverifyCollection {
+        tree_config: tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        payer: payer.pubkey(),
+        tree_creator_or_delegate: payer.pubkey(),
+        collection_authority,
+        collection_authority_record_pda: None,
+        collection_mint,
+        collection_metadata,
+        collection_edition,
+        bubblegum_signer,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        token_metadata_program: mpl_token_metadata::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(UnverifyCollectionInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+    });
+
+    push_proof_accounts(&mut unverify_collection_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(unverify_collection_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn set_and_verify_collection_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    collection_authority: String,
+    collection_mint: String,
+    collection_metadata: String,
+    collection_master_edition: String,
+    new_collection: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    metadata_args: MetadataArgsStruct,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: KeyPairInfo,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let collection_authority = Pubkey::from_str(&collection_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection authority")))?;
+
+    let collection_mint = Pubkey::from_str(&collection_mint)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection mint")))?;
+
+    let collection_metadata = if collection_metadata.is_empty() {
+        Metadata::find_pda(&collection_mint).0
+    } else {
+        Pubkey::from_str(&collection_metadata)
+            .map_err(|_| Error::Term(Box::new("Invalid pubkey format for collection metadata")))?
+    };
+
+    let collection_edition = if collection_master_edition.is_empty() {
+        MasterEdition::find_pda(&collection_mint).0
+    } else {
+        Pubkey::from_str(&collection_master_edition).map_err(|_| {
+            Error::Term(Box::new(
+                "Invalid pubkey format for collection master edition",
+            ))
+        })?
+    };
+
+    let new_collection = Pubkey::from_str(&new_collection)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for new collection")))?;
+
+    let payer = Keypair::from_bytes(&payer.secret)
+        .map_err(|_| Error::Term(Box::new("Invalid payer keypair")))?;
+
+    let message = to_rust_metadata_args(&metadata_args)?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let bubblegum_signer_seeds = &["collection_cpi".as_bytes()];
+    let (bubblegum_signer, _) =
+        Pubkey::find_program_address(bubblegum_signer_seeds, &mpl_bubblegum::ID);
+
+    let mut set_and_verify_ix = SetAndVerifyCollection {
+        tree_config: tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        payer: payer.pubkey(),
+        tree_creator_or_delegate: payer.pubkey(),
+        collection_authority,
+        collection_authority_record_pda: None,
+        collection_mint,
+        collection_metadata,
+        collection_edition,
+        bubblegum_signer,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        token_metadata_program: mpl_token_metadata::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(SetAndVerifyCollectionInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+        message,
+        collection: new_collection,
+    });
+
+    push_proof_accounts(&mut set_and_verify_ix.accounts, proof_addresses)?;
+
+    build_signed_transaction(set_and_verify_ix, &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_unsigned_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    new_leaf_owner: String,
+    merkle_tree: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: String,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let new_leaf_owner = Pubkey::from_str(&new_leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for new leaf owner")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let payer = Pubkey::from_str(&payer)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for payer")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut transfer_ix = Transfer {
+        tree_config: tree_authority,
+        leaf_owner: (leaf_owner, true),
+        leaf_delegate: (leaf_delegate, true),
+        new_leaf_owner,
+        merkle_tree,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(TransferInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    push_proof_accounts(&mut transfer_ix.accounts, proof_addresses)?;
+
+    build_unsigned_transaction(&[transfer_ix], &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn mint_v1_unsigned_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    payer: String,
+    tree_creator_or_delegate: String,
+    metadata_args: MetadataArgsStruct,
+    recent_blockhash: String,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let payer = Pubkey::from_str(&payer)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for payer")))?;
+
+    let tree_creator_or_delegate = Pubkey::from_str(&tree_creator_or_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree creator or delegate")))?;
+
+    let metadata = to_rust_metadata_args(&metadata_args)?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let mint_ix = MintV1 {
+        tree_config: tree_authority,
+        leaf_delegate,
+        leaf_owner,
+        merkle_tree,
+        payer,
+        tree_creator_or_delegate,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(MintV1InstructionArgs { metadata });
+
+    build_unsigned_transaction(&[mint_ix], &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn burn_unsigned_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: String,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let payer = Pubkey::from_str(&payer)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for payer")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut burn_ix = Burn {
+        tree_config: tree_authority,
+        leaf_owner: (leaf_owner, true),
+        leaf_delegate: (leaf_delegate, true),
+        merkle_tree,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(BurnInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    push_proof_accounts(&mut burn_ix.accounts, proof_addresses)?;
+
+    build_unsigned_transaction(&[burn_ix], &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn delegate_unsigned_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    previous_leaf_delegate: String,
+    new_leaf_delegate: String,
+    merkle_tree: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: String,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let previous_leaf_delegate = Pubkey::from_str(&previous_leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for previous leaf delegate")))?;
+
+    let new_leaf_delegate = Pubkey::from_str(&new_leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for new leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let payer = Pubkey::from_str(&payer)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for payer")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut delegate_ix = Delegate {
+        tree_config: tree_authority,
+        leaf_owner,
+        previous_leaf_delegate,
+        new_leaf_delegate,
+        merkle_tree,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(DelegateInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    push_proof_accounts(&mut delegate_ix.accounts, proof_addresses)?;
+
+    build_unsigned_transaction(&[delegate_ix], &payer, blockhash)
+}
+
+#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+pub fn redeem_unsigned_ix(
+    tree_authority: String,
+    leaf_owner: String,
+    leaf_delegate: String,
+    merkle_tree: String,
+    voucher: String,
+    root_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+    data_hash: Vec<u8>,
+    nonce: u64,
+    index: u32,
+    proof_addresses: Vec<String>,
+    recent_blockhash: String,
+    payer: String,
+) -> NifResult<TransactionStruct> {
+    let tree_authority = Pubkey::from_str(&tree_authority)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for tree authority")))?;
+
+    let leaf_owner = Pubkey::from_str(&leaf_owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf owner")))?;
+
+    let leaf_delegate = Pubkey::from_str(&leaf_delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for leaf delegate")))?;
+
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let voucher = Pubkey::from_str(&voucher)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for voucher")))?;
+
+    let payer = Pubkey::from_str(&payer)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for payer")))?;
+
+    let blockhash = Hash::from_str(&recent_blockhash)
+        .map_err(|_| Error::Term(Box::new("Invalid blockhash")))?;
+
+    let root = vec_to_array32(root_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut redeem_ix = Redeem {
+        tree_config: tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        merkle_tree,
+        voucher,
+        log_wrapper: spl_noop::ID,
+        compression_program: spl_account_compression::ID,
+        system_program: solana_program::system_program::ID,
+    }
+    .instruction(RedeemInstructionArgs {
+        root,
+        data_hash,
+        creator_hash,
+        nonce,
+        index,
+    });
+
+    push_proof_accounts(&mut redeem_ix.accounts, proof_addresses)?;
+
+    build_unsigned_transaction(&[redeem_ix], &payer, blockhash)
+}
+
+#[rustler::nif]
+pub fn sign_transaction(
+    serialized_message: Vec<u8>,
+    secrets: Vec<Vec<u8>>,
+) -> NifResult<TransactionStruct> {
+    let message: solana_program::message::Message = bincode::deserialize(&serialized_message)
+        .map_err(|_| Error::Term(Box::new("Failed to deserialize message")))?;
+
+    let mut keypairs = Vec::with_capacity(secrets.len());
+    for secret in &secrets {
+        let keypair = Keypair::from_bytes(secret)
+            .map_err(|_| Error::Term(Box::new("Invalid keypair in signer set")))?;
+        keypairs.push(keypair);
+    }
+
+    let blockhash = message.recent_blockhash;
+    let signer_pubkeys: Vec<String> = message
+        .account_keys
+        .iter()
+        .take(message.header.num_required_signatures as usize)
+        .map(|pubkey| pubkey.to_string())
+        .collect();
+    let mut transaction = Transaction::new_unsigned(message);
+    let signers: Vec<&Keypair> = keypairs.iter().collect();
+    transaction
+        .try_sign(&signers, blockhash)
+        .map_err(|_| Error::Term(Box::new("Failed to sign transaction")))?;
+
+    let serialized_tx = bincode::serialize(&transaction)
+        .map_err(|_| Error::Term(Box::new("Failed to serialize transaction")))?;
+
+    Ok(TransactionStruct {
+        message: serialized_tx,
+        signatures: transaction
+            .signatures
+            .iter()
+            .map(|sig| sig.as_ref().to_vec())
+            .collect(),
+        signers: signer_pubkeys,
+    })
+}
+
+#[rustler::nif]
+pub fn add_signature(
+    transaction: TransactionStruct,
+    pubkey: String,
+    signature_bytes: Vec<u8>,
+) -> NifResult<TransactionStruct> {
+    let position = transaction
+        .signers
+        .iter()
+        .position(|signer| signer == &pubkey)
+        .ok_or_else(|| Error::Term(Box::new("Pubkey is not a required signer")))?;
+
+    // The unsigned builders put a serialized `Message` in `message`; once a
+    // signature has been attached `message` holds a full `Transaction`. Accept
+    // either so signatures can be attached one at a time.
+    let message: solana_program::message::Message =
+        match bincode::deserialize::<Transaction>(&transaction.message) {
+            Ok(existing) => existing.message,
+            Err(_) => bincode::deserialize(&transaction.message)
+                .map_err(|_| Error::Term(Box::new("Failed to deserialize transaction message")))?,
+        };
+
+    let mut signatures = transaction.signatures;
+    if signatures.len() < transaction.signers.len() {
+        signatures.resize(transaction.signers.len(), Vec::new());
+    }
+    signatures[position] = signature_bytes;
+
+    // Reassemble a wire `Transaction` from the message and the collected
+    // signature slots (empty slots stay as the default, zeroed signature) so
+    // the returned `message` is a submittable transaction, matching the signed
+    // builders and `sign_transaction`.
+    let wire_signatures = signatures
+        .iter()
+        .map(|bytes| {
+            if bytes.is_empty() {
+                Ok(Signature::default())
+            } else {
+                Signature::try_from(bytes.as_slice())
+                    .map_err(|_| Error::Term(Box::new("Invalid signature bytes")))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let finalized = Transaction {
+        signatures: wire_signatures,
+        message,
+    };
+
+    let serialized_tx = bincode::serialize(&finalized)
+        .map_err(|_| Error::Term(Box::new("Failed to serialize transaction")))?;
+
+    Ok(TransactionStruct {
+        message: serialized_tx,
+        signatures,
+        signers: transaction.signers,
+    })
+}
+
+#[rustler::nif]
+pub fn get_tree_authority_pda_address(merkle_tree: String) -> NifResult<String> {
+    let merkle_tree = Pubkey::from_str(&merkle_tree)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for merkle tree")))?;
+
+    let (tree_authority, _) = TreeConfig::find_pda(&merkle_tree);
+    Ok(tree_authority.to_string())
+}
+
+#[rustler::nif]
+pub fn hash_metadata(metadata_args: MetadataArgsStruct) -> NifResult<Vec<u8>> {
+    let metadata = to_rust_metadata_args(&metadata_args)?;
+
+    let serialized = metadata
+        .try_to_vec()
+        .map_err(|_| Error::Term(Box::new("Failed to serialize metadata args")))?;
+
+    let hashed_metadata = keccak::hashv(&[serialized.as_ref()]).to_bytes();
+    let data_hash = keccak::hashv(&[
+        hashed_metadata.as_ref(),
+        &metadata.seller_fee_basis_points.to_le_bytes(),
+    ])
+    .to_bytes();
+
+    Ok(data_hash.to_vec())
+}
+
+#[rustler::nif]
+pub fn hash_creators(creators: Vec<CreatorStruct>) -> NifResult<Vec<u8>> {
+    let mut creator_data = Vec::with_capacity(creators.len() * 34);
+    for creator in &creators {
+        let address = Pubkey::from_str(&creator.address)
+            .map_err(|_| Error::Term(Box::new("Invalid pubkey format for creator")))?;
+        creator_data.extend_from_slice(address.as_ref());
+        creator_data.push(creator.verified as u8);
+        creator_data.push(creator.share);
+    }
+
+    let creator_hash = keccak::hashv(&[creator_data.as_ref()]).to_bytes();
+    Ok(creator_hash.to_vec())
+}
+
+#[rustler::nif]
+pub fn hash_leaf(
+    asset_id: String,
+    owner: String,
+    delegate: String,
+    nonce: u64,
+    data_hash: Vec<u8>,
+    creator_hash: Vec<u8>,
+) -> NifResult<Vec<u8>> {
+    let asset_id = Pubkey::from_str(&asset_id)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for asset id")))?;
+
+    let owner = Pubkey::from_str(&owner)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for owner")))?;
+
+    let delegate = Pubkey::from_str(&delegate)
+        .map_err(|_| Error::Term(Box::new("Invalid pubkey format for delegate")))?;
+
+    let data_hash = vec_to_array32(data_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let creator_hash = vec_to_array32(creator_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let leaf_hash = keccak::hashv(&[
+        &[1u8],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        data_hash.as_ref(),
+        creator_hash.as_ref(),
+    ])
+    .to_bytes();
+
+    Ok(leaf_hash.to_vec())
+}
+
+#[rustler::nif]
+pub fn verify_proof(
+    leaf_hash: Vec<u8>,
+    root: Vec<u8>,
+    proof: Vec<Vec<u8>>,
+    index: u32,
+) -> NifResult<bool> {
+    // The walk consumes one sibling per proof node, bottom up. The tree depth
+    // is not an argument, so the proof length is not checked on its own: a
+    // proof of the wrong length recomputes a different root and the result is
+    // `false`, not an error.
+    let mut current = vec_to_array32(leaf_hash).map_err(|err| Error::Term(Box::new(err)))?;
+    let root = vec_to_array32(root).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut index = index;
+    for sibling in proof {
+        let sibling = vec_to_array32(sibling).map_err(|err| Error::Term(Box::new(err)))?;
+        current = if index & 1 == 0 {
+            keccak::hashv(&[current.as_ref(), sibling.as_ref()]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling.as_ref(), current.as_ref()]).to_bytes()
+        };
+        index >>= 1;
+    }
+
+    Ok(current == root)
+}
+
+#[derive(NifStruct)]
+#[module = "BubblegumNifs.MerkleProof"]
+pub struct MerkleProofStruct {
+    pub proof: Vec<Vec<u8>>,
+    pub root: Vec<u8>,
+}
+
+// A single recorded mutation of the tree: the leaf that changed plus the
+// sibling path that was valid for it immediately after the update. Kept in the
+// ring buffer so the most recent proof can be served without walking the tree,
+// the way `spl-account-compression` keeps a changelog of the last
+// `max_buffer_size` updates.
+struct ChangeLog {
+    index: u32,
+    path: Vec<[u8; 32]>,
+}
+
+// Append-only, right-filled concurrent merkle tree modeled on
+// `spl-account-compression` / incremental-merkle-tree. Only the nodes touched
+// by an update are stored; any node never written is the canonical "empty"
+// node for its level (all zeros at the leaves, the hash of two empty children
+// above), so the full `2^max_depth` leaf space never has to be materialized.
+struct MerkleTree {
+    max_depth: usize,
+    max_buffer_size: usize,
+    empty_nodes: Vec<[u8; 32]>,
+    // Current hash of every node ever written, keyed by `(level, index)` with
+    // level 0 being the leaves.
+    nodes: HashMap<(usize, u64), [u8; 32]>,
+    num_leaves: u64,
+    change_logs: VecDeque<ChangeLog>,
+    root: [u8; 32],
+}
+
+impl MerkleTree {
+    fn new(max_depth: usize, max_buffer_size: usize) -> Self {
+        let mut empty_nodes = Vec::with_capacity(max_depth + 1);
+        empty_nodes.push([0u8; 32]);
+        for level in 0..max_depth {
+            let lower = empty_nodes[level];
+            empty_nodes.push(keccak::hashv(&[lower.as_ref(), lower.as_ref()]).to_bytes());
+        }
+
+        let root = empty_nodes[max_depth];
+
+        MerkleTree {
+            max_depth,
+            max_buffer_size,
+            empty_nodes,
+            nodes: HashMap::new(),
+            num_leaves: 0,
+            change_logs: VecDeque::new(),
+            root,
+        }
+    }
+
+    // Read a node, defaulting to the empty node for its level when it has never
+    // been written.
+    fn node(&self, level: usize, index: u64) -> [u8; 32] {
+        self.nodes
+            .get(&(level, index))
+            .copied()
+            .unwrap_or(self.empty_nodes[level])
+    }
+
+    // Set `leaf` at `index` and walk only that leaf's path to the root,
+    // rehashing each parent from its two children. Records the refreshed sibling
+    // path as a changelog entry, evicting the oldest once the ring buffer fills.
+    fn commit_update(&mut self, index: u32, leaf: [u8; 32]) {
+        let mut node_index = index as u64;
+        self.nodes.insert((0, node_index), leaf);
+
+        let mut current = leaf;
+        let mut siblings = Vec::with_capacity(self.max_depth);
+        for level in 0..self.max_depth {
+            let sibling = self.node(level, node_index ^ 1);
+            siblings.push(sibling);
+
+            let (left, right) = if node_index & 1 == 0 {
+                (current, sibling)
+            } else {
+                (sibling, current)
+            };
+            current = keccak::hashv(&[left.as_ref(), right.as_ref()]).to_bytes();
+            node_index >>= 1;
+            self.nodes.insert((level + 1, node_index), current);
+        }
+        self.root = current;
+
+        if self.change_logs.len() == self.max_buffer_size {
+            self.change_logs.pop_front();
+        }
+        self.change_logs.push_back(ChangeLog {
+            index,
+            path: siblings,
+        });
+    }
+
+    // Collect the sibling list for `index`, bottom up, as the raw 32-byte nodes
+    // `verify_proof` takes; `transfer_ix` takes the same nodes as base58 pubkey
+    // strings, which this does not produce. When the most recent update touched
+    // this very leaf its recorded path is still current, so the changelog serves
+    // the proof without touching the node map.
+    fn proof(&self, index: u32) -> Vec<[u8; 32]> {
+        if let Some(last) = self.change_logs.back() {
+            if last.index == index {
+                return last.path.clone();
+            }
+        }
+
+        let mut node_index = index as u64;
+        let mut proof = Vec::with_capacity(self.max_depth);
+        for level in 0..self.max_depth {
+            proof.push(self.node(level, node_index ^ 1));
+            node_index >>= 1;
+        }
+        proof
+    }
+}
+
+pub struct MerkleTreeResource(Mutex<MerkleTree>);
+
+#[rustler::resource_impl]
+impl rustler::Resource for MerkleTreeResource {}
+
+#[rustler::nif]
+pub fn init_tree(max_depth: u32, max_buffer_size: u32) -> NifResult<ResourceArc<MerkleTreeResource>> {
+    if max_depth == 0 || max_depth > 30 {
+        return Err(Error::Term(Box::new("max_depth must be between 1 and 30")));
+    }
+
+    if max_buffer_size == 0 {
+        return Err(Error::Term(Box::new("max_buffer_size must be greater than 0")));
+    }
+
+    let tree = MerkleTree::new(max_depth as usize, max_buffer_size as usize);
+    Ok(ResourceArc::new(MerkleTreeResource(Mutex::new(tree))))
+}
+
+#[rustler::nif]
+pub fn append_leaf(
+    tree: ResourceArc<MerkleTreeResource>,
+    leaf_hash: Vec<u8>,
+) -> NifResult<u32> {
+    let leaf = vec_to_array32(leaf_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut tree = tree
+        .0
+        .lock()
+        .map_err(|_| Error::Term(Box::new("Merkle tree lock poisoned")))?;
+
+    if tree.num_leaves >= 1u64 << tree.max_depth {
+        return Err(Error::Term(Box::new("Merkle tree is full")));
+    }
+
+    let index = tree.num_leaves as u32;
+    tree.num_leaves += 1;
+    tree.commit_update(index, leaf);
+
+    Ok(index)
+}
+
+#[rustler::nif]
+pub fn replace_leaf(
+    tree: ResourceArc<MerkleTreeResource>,
+    index: u32,
+    new_leaf_hash: Vec<u8>,
+) -> NifResult<bool> {
+    let leaf = vec_to_array32(new_leaf_hash).map_err(|err| Error::Term(Box::new(err)))?;
+
+    let mut tree = tree
+        .0
+        .lock()
+        .map_err(|_| Error::Term(Box::new("Merkle tree lock poisoned")))?;
+
+    if index as u64 >= tree.num_leaves {
+        return Err(Error::Term(Box::new("Leaf index out of range")));
+    }
+
+    tree.commit_update(index, leaf);
+
+    Ok(true)
+}
+
+#[rustler::nif]
+pub fn get_proof(
+    tree: ResourceArc<MerkleTreeResource>,
+    index: u32,
+) -> NifResult<MerkleProofStruct> {
+    let tree = tree
+        .0
+        .lock()
+        .map_err(|_| Error::Term(Box::new("Merkle tree lock poisoned")))?;
+
+    if index as u64 >= tree.num_leaves {
+        return Err(Error::Term(Box::new("Leaf index out of range")));
+    }
+
+    let proof = tree
+        .proof(index)
+        .into_iter()
+        .map(|node| node.to_vec())
+        .collect();
+
+    Ok(MerkleProofStruct {
+        proof,
+        root: tree.root.to_vec(),
+    })
 }